@@ -0,0 +1,76 @@
+// Copyright (c) 2016 Yusuke Sasaki
+//
+// This software is released under the MIT License.
+// See http://opensource.org/licenses/mit-license.php or <LICENSE>.
+
+use ffi;
+
+use std::ptr::null_mut;
+
+use error::{Error, Result};
+use util;
+
+use super::param::ParamSet;
+use super::{Env, FromRaw};
+
+/// Builds an `Env` in two phases, mirroring the C API's `GRBemptyenv` /
+/// `GRBstartenv` split.
+///
+/// Unlike `Env::new`, which connects as soon as the environment is created,
+/// an `EnvBuilder` lets licensing parameters (`CloudAccessID`, `LicenseID`,
+/// `WLSAccessID`, `WLSSecret`, `ServerPassword`, ...) be set before the
+/// environment tries to authenticate, which is required to reach a compute
+/// server, WLS, or Instant Cloud.
+pub struct EnvBuilder {
+  env: *mut ffi::GRBenv,
+}
+
+impl EnvBuilder {
+  /// Create an unstarted environment (`GRBemptyenv`).
+  pub fn new() -> Result<EnvBuilder> {
+    let mut env = null_mut();
+    let error = unsafe { ffi::GRBemptyenv(&mut env) };
+    if error != 0 {
+      return Err(Error::FromAPI(super::get_error_msg(env), error));
+    }
+    Ok(EnvBuilder { env: env })
+  }
+
+  /// Set a parameter on the unstarted environment.
+  pub fn param<P, V>(self, param: P, value: V) -> Result<EnvBuilder>
+    where P: ParamSet<V>
+  {
+    let error = unsafe { P::set_param(self.env, param.into().as_ptr(), util::FromRaw::from(value)) };
+    if error != 0 {
+      return Err(Error::FromAPI(super::get_error_msg(self.env), error));
+    }
+    Ok(self)
+  }
+
+  /// Connect the environment (`GRBstartenv`) and hand back a usable `Env`.
+  pub fn start(mut self) -> Result<Env> {
+    let error = unsafe { ffi::GRBstartenv(self.env) };
+    if error != 0 {
+      return Err(Error::FromAPI(super::get_error_msg(self.env), error));
+    }
+    let env = self.env;
+    self.env = null_mut();
+    Ok(Env::from_raw(env))
+  }
+}
+
+impl Drop for EnvBuilder {
+  fn drop(&mut self) {
+    if !self.env.is_null() {
+      unsafe { ffi::GRBfreeenv(self.env) };
+    }
+  }
+}
+
+#[test]
+fn param_set_before_start_is_visible_once_started() {
+  use super::param;
+  let env = EnvBuilder::new().unwrap().param(param::IISMethod, 1).unwrap().start().unwrap();
+  let iis_method = env.get(param::IISMethod).unwrap();
+  assert_eq!(iis_method, 1);
+}
@@ -0,0 +1,193 @@
+// Copyright (c) 2016 Yusuke Sasaki
+//
+// This software is released under the MIT License.
+// See http://opensource.org/licenses/mit-license.php or <LICENSE>.
+
+use std::env as std_env;
+
+use error::{Error, Result};
+
+use super::param::{IntParam, DoubleParam, StringParam};
+use super::Env;
+
+/// A single parameter value, already resolved to a concrete `IntParam`,
+/// `DoubleParam`, or `StringParam`. Programmatic overrides are stored in this
+/// form, and each matching environment variable is resolved to it when a
+/// `ParamConfig` is applied; `.prm` files are read by `Env::read_params`
+/// instead and do not pass through it.
+enum ResolvedParam {
+  Int(IntParam, i32),
+  Double(DoubleParam, f64),
+  String(StringParam, String),
+}
+
+impl ResolvedParam {
+  fn apply(&self, env: &mut Env) -> Result<()> {
+    match *self {
+      ResolvedParam::Int(param, value) => env.set(param, value),
+      ResolvedParam::Double(param, value) => env.set(param, value),
+      ResolvedParam::String(ref param, ref value) => env.set(*param, value.clone()),
+    }
+  }
+}
+
+/// Resolve a bare parameter name (e.g. `"Threads"`, taken from a
+/// `GUROBI_Threads` environment variable) to the typed parameter it names.
+///
+/// `gurobi_sys` gives us `IntParam`/`DoubleParam`/`StringParam` as plain
+/// enums with no reverse string lookup, so this table is the single place
+/// that maps a name to its variant; every name/type pair it knows about is
+/// listed once, here, rather than guessed at via a trait those types don't
+/// provably implement. Extend it as new parameters are wired up.
+macro_rules! param_table {
+  (int: [$($iname:ident),* $(,)*],
+   double: [$($dname:ident),* $(,)*],
+   string: [$($sname:ident),* $(,)*] $(,)*) => {
+    fn resolve_by_name(name: &str, value: &str) -> Result<ResolvedParam> {
+      $(
+        if name == stringify!($iname) {
+          let parsed = try!(value.parse::<i32>().map_err(|e| {
+            Error::InvalidParameter(format!("invalid integer value {:?} for parameter {}: {}", value, name, e))
+          }));
+          return Ok(ResolvedParam::Int(IntParam::$iname, parsed));
+        }
+      )*
+      $(
+        if name == stringify!($dname) {
+          let parsed = try!(value.parse::<f64>().map_err(|e| {
+            Error::InvalidParameter(format!("invalid float value {:?} for parameter {}: {}", value, name, e))
+          }));
+          return Ok(ResolvedParam::Double(DoubleParam::$dname, parsed));
+        }
+      )*
+      $(
+        if name == stringify!($sname) {
+          return Ok(ResolvedParam::String(StringParam::$sname, value.to_owned()));
+        }
+      )*
+      Err(Error::InvalidParameter(format!("unknown parameter name: {}", name)))
+    }
+  }
+}
+
+param_table! {
+  int: [Threads, IISMethod],
+  double: [TimeLimit, MIPGap],
+  string: [LogFile, CloudAccessID, CloudSecretKey, LicenseID, WLSAccessID, WLSSecret, ServerPassword],
+}
+
+/// One ordered source of parameter values. Later sources in a `ParamConfig`
+/// take precedence over earlier ones.
+enum Source {
+  File(String),
+  EnvPrefix(String),
+  Override(ResolvedParam),
+}
+
+/// Merges parameter values from `.prm` files, `GUROBI_<PARAM>`-style
+/// environment variables, and explicit overrides into one ordered set,
+/// then applies them to an `Env` in source order.
+///
+/// ```ignore
+/// ParamConfig::builder()
+///     .with_file("gurobi.prm")
+///     .with_env_prefix("GUROBI_")
+///     .with_override(param::Threads, 4)
+///     .apply(&mut env)?;
+/// ```
+pub struct ParamConfig {
+  sources: Vec<Source>,
+}
+
+impl ParamConfig {
+  /// Start building a `ParamConfig`. There is no separate "defaults" source:
+  /// an `Env` already carries Gurobi's built-in defaults until a source
+  /// here overrides them.
+  pub fn builder() -> ParamConfig {
+    ParamConfig { sources: Vec::new() }
+  }
+
+  /// Apply a `.prm` file, in the format read by `Env::read_params`.
+  pub fn with_file(mut self, filename: &str) -> ParamConfig {
+    self.sources.push(Source::File(filename.to_owned()));
+    self
+  }
+
+  /// Apply every `<prefix><ParamName>` environment variable found, dispatched
+  /// to the matching `IntParam`/`DoubleParam`/`StringParam` by name.
+  pub fn with_env_prefix(mut self, prefix: &str) -> ParamConfig {
+    self.sources.push(Source::EnvPrefix(prefix.to_owned()));
+    self
+  }
+
+  /// Apply an explicit integer override.
+  pub fn with_override(mut self, param: IntParam, value: i32) -> ParamConfig {
+    self.sources.push(Source::Override(ResolvedParam::Int(param, value)));
+    self
+  }
+
+  /// Apply an explicit floating-point override.
+  pub fn with_double_override(mut self, param: DoubleParam, value: f64) -> ParamConfig {
+    self.sources.push(Source::Override(ResolvedParam::Double(param, value)));
+    self
+  }
+
+  /// Apply an explicit string override.
+  pub fn with_string_override(mut self, param: StringParam, value: &str) -> ParamConfig {
+    self.sources.push(Source::Override(ResolvedParam::String(param, value.to_owned())));
+    self
+  }
+
+  /// Apply every source to `env`, in the order they were added.
+  pub fn apply(self, env: &mut Env) -> Result<()> {
+    for source in self.sources {
+      match source {
+        Source::File(filename) => try!(env.read_params(&filename)),
+        Source::EnvPrefix(prefix) => {
+          for (key, value) in std_env::vars() {
+            if key.starts_with(&prefix) {
+              let name = &key[prefix.len()..];
+              try!(try!(resolve_by_name(name, &value)).apply(env));
+            }
+          }
+        }
+        Source::Override(resolved) => try!(resolved.apply(env)),
+      }
+    }
+    Ok(())
+  }
+}
+
+#[test]
+fn resolve_by_name_dispatches_int_param() {
+  match resolve_by_name("Threads", "4").unwrap() {
+    ResolvedParam::Int(_, value) => assert_eq!(value, 4),
+    _ => panic!("expected an integer parameter"),
+  }
+}
+
+#[test]
+fn resolve_by_name_dispatches_double_param() {
+  match resolve_by_name("MIPGap", "0.01").unwrap() {
+    ResolvedParam::Double(_, value) => assert_eq!(value, 0.01),
+    _ => panic!("expected a double parameter"),
+  }
+}
+
+#[test]
+fn resolve_by_name_dispatches_string_param() {
+  match resolve_by_name("LogFile", "gurobi.log").unwrap() {
+    ResolvedParam::String(_, value) => assert_eq!(value, "gurobi.log"),
+    _ => panic!("expected a string parameter"),
+  }
+}
+
+#[test]
+fn resolve_by_name_rejects_unknown_names() {
+  assert!(resolve_by_name("NotARealGurobiParam", "1").is_err());
+}
+
+#[test]
+fn resolve_by_name_rejects_unparseable_values() {
+  assert!(resolve_by_name("Threads", "not-a-number").is_err());
+}
@@ -4,6 +4,11 @@
 // See http://opensource.org/licenses/mit-license.php or <LICENSE>.
 
 pub mod param;
+pub mod builder;
+pub mod config;
+
+pub use self::builder::EnvBuilder;
+pub use self::config::ParamConfig;
 
 use ffi;
 
@@ -34,6 +39,9 @@ pub struct Env(Rc<EnvRep>);
 
 impl Env {
   /// Create an environment with log file
+  ///
+  /// For a compute server, WLS, or Instant Cloud license, where credentials
+  /// must be set before the environment connects, use `EnvBuilder` instead.
   pub fn new(logfilename: &str) -> Result<Env> {
     let mut env = null_mut();
     let logfilename = try!(CString::new(logfilename));
@@ -93,7 +101,9 @@ impl Env {
   }
 
   /// Query the value of a parameter
-  pub fn get<P: param::ParamBase>(&self, param: P) -> Result<P::Out> {
+  pub fn get<P, V>(&self, param: P) -> Result<V>
+    where P: param::ParamGet<V>
+  {
     use util::AsRawPtr;
     let mut value: P::Buf = util::Init::init();
     try!(self.check_apicall(unsafe { P::get_param(self.0.ptr, param.into().as_ptr(), value.as_rawptr()) }));
@@ -101,8 +111,18 @@ impl Env {
     Ok(util::Into::into(value))
   }
 
+  /// Query a parameter's current value along with its min, max, and default,
+  /// so a tuning value can be validated before it is set.
+  pub fn get_info<P, V>(&self, param: P) -> Result<param::ParamInfo<V>>
+    where P: param::ParamInfoGet<V>
+  {
+    unsafe { P::get_param_info(self.0.ptr, param.into().as_ptr()) }
+  }
+
   /// Set the value of a parameter
-  pub fn set<P: param::ParamBase>(&mut self, param: P, value: P::Out) -> Result<()> {
+  pub fn set<P, V>(&mut self, param: P, value: V) -> Result<()>
+    where P: param::ParamSet<V>
+  {
     self.check_apicall(unsafe {
       P::set_param(self.0.ptr,
                    param.into().as_ptr(),
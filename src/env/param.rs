@@ -0,0 +1,203 @@
+// Copyright (c) 2016 Yusuke Sasaki
+//
+// This software is released under the MIT License.
+// See http://opensource.org/licenses/mit-license.php or <LICENSE>.
+
+use ffi;
+
+use std::ffi::CString;
+
+use error::{Error, Result};
+use util;
+
+pub use ffi::{IntParam, DoubleParam, StringParam};
+pub use ffi::IntParam::*;
+pub use ffi::DoubleParam::*;
+pub use ffi::StringParam::*;
+
+/// The size of the buffer Gurobi expects callers to pass to
+/// `GRBgetstrparam`; the C API writes into it directly and does not
+/// allocate one itself.
+pub const GRB_MAX_STRLEN: usize = 512;
+
+/// Provides the raw accessor used to query the current value of a Gurobi
+/// parameter. Split out from `ParamSet` so that read-only values (and
+/// introspection, see `ParamInfo`) can be expressed without a setter.
+pub trait ParamGet<Out>: Sized + Into<CString> {
+  type Buf: util::Init + util::AsRawPtr<Self::RawGet> + util::Into<Out>;
+  type RawGet;
+
+  unsafe fn get_param(env: *mut ffi::GRBenv, paramname: *const ffi::c_char, value: Self::RawGet) -> ffi::c_int;
+}
+
+/// Provides the raw accessor used to update the value of a Gurobi parameter.
+pub trait ParamSet<Out>: Sized + Into<CString> {
+  type RawSet: util::FromRaw<Out>;
+
+  unsafe fn set_param(env: *mut ffi::GRBenv, paramname: *const ffi::c_char, value: Self::RawSet) -> ffi::c_int;
+}
+
+
+impl ParamGet<i32> for IntParam {
+  type Buf = i32;
+  type RawGet = *mut ffi::c_int;
+
+  #[inline(always)]
+  unsafe fn get_param(env: *mut ffi::GRBenv, paramname: *const ffi::c_char, value: *mut ffi::c_int) -> ffi::c_int {
+    ffi::GRBgetintparam(env, paramname, value)
+  }
+}
+
+impl ParamSet<i32> for IntParam {
+  type RawSet = ffi::c_int;
+
+  #[inline(always)]
+  unsafe fn set_param(env: *mut ffi::GRBenv, paramname: *const ffi::c_char, value: ffi::c_int) -> ffi::c_int {
+    ffi::GRBsetintparam(env, paramname, value)
+  }
+}
+
+impl ParamGet<f64> for DoubleParam {
+  type Buf = f64;
+  type RawGet = *mut ffi::c_double;
+
+  #[inline(always)]
+  unsafe fn get_param(env: *mut ffi::GRBenv, paramname: *const ffi::c_char, value: *mut ffi::c_double) -> ffi::c_int {
+    ffi::GRBgetdblparam(env, paramname, value)
+  }
+}
+
+impl ParamSet<f64> for DoubleParam {
+  type RawSet = ffi::c_double;
+
+  #[inline(always)]
+  unsafe fn set_param(env: *mut ffi::GRBenv, paramname: *const ffi::c_char, value: ffi::c_double) -> ffi::c_int {
+    ffi::GRBsetdblparam(env, paramname, value)
+  }
+}
+
+impl ParamGet<String> for StringParam {
+  type Buf = StrBuf;
+  type RawGet = *mut ffi::c_char;
+
+  #[inline(always)]
+  unsafe fn get_param(env: *mut ffi::GRBenv, paramname: *const ffi::c_char, value: *mut ffi::c_char) -> ffi::c_int {
+    ffi::GRBgetstrparam(env, paramname, value)
+  }
+}
+
+impl ParamSet<String> for StringParam {
+  type RawSet = *const ffi::c_char;
+
+  #[inline(always)]
+  unsafe fn set_param(env: *mut ffi::GRBenv, paramname: *const ffi::c_char, value: *const ffi::c_char) -> ffi::c_int {
+    ffi::GRBsetstrparam(env, paramname, value)
+  }
+}
+
+
+/// Receives a `GRBgetstrparam` value. Pre-allocated to `GRB_MAX_STRLEN`
+/// bytes, since Gurobi writes into the buffer it is given rather than
+/// growing one of its own.
+pub struct StrBuf(Vec<ffi::c_char>);
+
+impl util::Init for StrBuf {
+  fn init() -> StrBuf {
+    StrBuf(vec![0; GRB_MAX_STRLEN])
+  }
+}
+
+impl util::AsRawPtr<*mut ffi::c_char> for StrBuf {
+  fn as_rawptr(&mut self) -> *mut ffi::c_char {
+    self.0.as_mut_ptr()
+  }
+}
+
+impl util::Into<String> for StrBuf {
+  fn into(self) -> String {
+    cstr_buf_to_string(&self.0)
+  }
+}
+
+/// Truncate a NUL-terminated `GRB_MAX_STRLEN` buffer at its terminator and
+/// convert the rest to a `String`.
+fn cstr_buf_to_string(buf: &[ffi::c_char]) -> String {
+  let bytes: Vec<u8> = buf.iter().take_while(|&&c| c != 0).map(|&c| c as u8).collect();
+  String::from_utf8_lossy(&bytes).into_owned()
+}
+
+
+/// The current value of a parameter alongside the bounds and default
+/// Gurobi reports for it, as returned by `GRBget{int,dbl,str}paraminfo`.
+/// `min`/`max` are `None` for string parameters, which have no bounds.
+pub struct ParamInfo<V> {
+  pub current: V,
+  pub min: Option<V>,
+  pub max: Option<V>,
+  pub default: V,
+}
+
+/// Provides parameter bound/default introspection, in addition to the
+/// current-value query `ParamGet` already provides.
+pub trait ParamInfoGet<V>: ParamGet<V> {
+  unsafe fn get_param_info(env: *mut ffi::GRBenv, paramname: *const ffi::c_char) -> Result<ParamInfo<V>>;
+}
+
+impl ParamInfoGet<i32> for IntParam {
+  unsafe fn get_param_info(env: *mut ffi::GRBenv, paramname: *const ffi::c_char) -> Result<ParamInfo<i32>> {
+    let (mut current, mut min, mut max, mut default) = (0, 0, 0, 0);
+    let error = ffi::GRBgetintparaminfo(env, paramname, &mut current, &mut min, &mut max, &mut default);
+    if error != 0 {
+      return Err(Error::FromAPI(super::get_error_msg(env), error));
+    }
+    Ok(ParamInfo {
+      current: current,
+      min: Some(min),
+      max: Some(max),
+      default: default,
+    })
+  }
+}
+
+impl ParamInfoGet<f64> for DoubleParam {
+  unsafe fn get_param_info(env: *mut ffi::GRBenv, paramname: *const ffi::c_char) -> Result<ParamInfo<f64>> {
+    let (mut current, mut min, mut max, mut default) = (0.0, 0.0, 0.0, 0.0);
+    let error = ffi::GRBgetdblparaminfo(env, paramname, &mut current, &mut min, &mut max, &mut default);
+    if error != 0 {
+      return Err(Error::FromAPI(super::get_error_msg(env), error));
+    }
+    Ok(ParamInfo {
+      current: current,
+      min: Some(min),
+      max: Some(max),
+      default: default,
+    })
+  }
+}
+
+impl ParamInfoGet<String> for StringParam {
+  unsafe fn get_param_info(env: *mut ffi::GRBenv, paramname: *const ffi::c_char) -> Result<ParamInfo<String>> {
+    let mut current = vec![0 as ffi::c_char; GRB_MAX_STRLEN];
+    let mut default = vec![0 as ffi::c_char; GRB_MAX_STRLEN];
+    let error = ffi::GRBgetstrparaminfo(env, paramname, current.as_mut_ptr(), default.as_mut_ptr());
+    if error != 0 {
+      return Err(Error::FromAPI(super::get_error_msg(env), error));
+    }
+    Ok(ParamInfo {
+      current: cstr_buf_to_string(&current),
+      min: None,
+      max: None,
+      default: cstr_buf_to_string(&default),
+    })
+  }
+}
+
+#[test]
+fn get_info_reports_current_within_its_own_bounds() {
+  use super::Env;
+  let env = Env::new("").unwrap();
+  let info = env.get_info(IISMethod).unwrap();
+  assert_eq!(info.current, info.default);
+  let (min, max) = (info.min.unwrap(), info.max.unwrap());
+  assert!(min <= info.current && info.current <= max);
+}
@@ -0,0 +1,48 @@
+// Copyright (c) 2016 Yusuke Sasaki
+//
+// This software is released under the MIT License.
+// See http://opensource.org/licenses/mit-license.php or <LICENSE>.
+
+use std::error;
+use std::ffi::NulError;
+use std::fmt;
+
+pub type Result<T> = ::std::result::Result<T, Error>;
+
+/// Crate-wide error type.
+#[derive(Debug)]
+pub enum Error {
+  /// An error reported by the Gurobi C API, with its error message and code.
+  FromAPI(String, i32),
+  /// A string passed to the API contained an interior NUL byte.
+  NulError(NulError),
+  /// A value supplied outside the C API call itself (e.g. parsing a
+  /// parameter name or value) was invalid.
+  InvalidParameter(String),
+}
+
+impl fmt::Display for Error {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      Error::FromAPI(ref message, code) => write!(f, "{} (error code {})", message, code),
+      Error::NulError(ref err) => err.fmt(f),
+      Error::InvalidParameter(ref message) => write!(f, "{}", message),
+    }
+  }
+}
+
+impl error::Error for Error {
+  fn description(&self) -> &str {
+    match *self {
+      Error::FromAPI(ref message, _) => message,
+      Error::NulError(ref err) => err.description(),
+      Error::InvalidParameter(ref message) => message,
+    }
+  }
+}
+
+impl From<NulError> for Error {
+  fn from(err: NulError) -> Error {
+    Error::NulError(err)
+  }
+}
@@ -0,0 +1,95 @@
+// Copyright (c) 2016 Yusuke Sasaki
+//
+// This software is released under the MIT License.
+// See http://opensource.org/licenses/mit-license.php or <LICENSE>.
+
+use ffi;
+
+use std::ptr::null_mut;
+
+use env::param::{ParamGet, ParamSet};
+use env::Env;
+use error::{Error, Result};
+use util;
+
+/// A Gurobi model, tied to the environment it was created from.
+pub struct Model {
+  env: Env,
+  model: *mut ffi::GRBmodel,
+}
+
+impl Model {
+  /// Wrap a `GRBmodel` already created from `env`.
+  pub fn new(env: Env, model: *mut ffi::GRBmodel) -> Result<Model> {
+    Ok(Model { env: env, model: model })
+  }
+
+  /// The environment this model was created from. Kept alongside the model
+  /// so it outlives it; see `model_env()` for the model's own private copy.
+  pub fn get_env(&self) -> &Env {
+    &self.env
+  }
+
+  /// The model's own environment (`GRBgetenv`). Each `Model` keeps a private
+  /// copy distinct from the `Env` it was created from, so parameters must be
+  /// read and set through this one to affect the model.
+  fn model_env(&self) -> *mut ffi::GRBenv {
+    unsafe { ffi::GRBgetenv(self.model) }
+  }
+
+  /// Build an error from the model's private env, not `self.env`: the last
+  /// error message is per-env-handle state, and `model_env()` is a distinct
+  /// handle from the one `self.env` wraps.
+  fn error_from_model_api(&self, error: ffi::c_int) -> Error {
+    Error::FromAPI(unsafe { util::from_c_str(ffi::GRBgeterrormsg(self.model_env())) }, error)
+  }
+
+  /// Query the value of a parameter on this model's private environment.
+  pub fn get_param<P, V>(&self, param: P) -> Result<V>
+    where P: ParamGet<V>
+  {
+    let mut value: P::Buf = util::Init::init();
+    let error = unsafe {
+      P::get_param(self.model_env(), param.into().as_ptr(), util::AsRawPtr::as_rawptr(&mut value))
+    };
+    if error != 0 {
+      return Err(self.error_from_model_api(error));
+    }
+    Ok(util::Into::into(value))
+  }
+
+  /// Set the value of a parameter on this model's private environment, so
+  /// models sharing one `Env` can still be tuned independently.
+  pub fn set_param<P, V>(&mut self, param: P, value: V) -> Result<()>
+    where P: ParamSet<V>
+  {
+    let error = unsafe { P::set_param(self.model_env(), param.into().as_ptr(), util::FromRaw::from(value)) };
+    if error != 0 {
+      return Err(self.error_from_model_api(error));
+    }
+    Ok(())
+  }
+}
+
+impl Drop for Model {
+  fn drop(&mut self) {
+    unsafe { ffi::GRBfreemodel(self.model) };
+    self.model = null_mut();
+  }
+}
+
+#[test]
+fn model_params_do_not_leak_between_sibling_models() {
+  use env::param;
+  use env::Env;
+
+  let env = Env::new("").unwrap();
+  let mut model_a = env.new_model("a").unwrap();
+  let model_b = env.new_model("b").unwrap();
+
+  let default = model_b.get_param(param::IISMethod).unwrap();
+  model_a.set_param(param::IISMethod, default + 1).unwrap();
+
+  assert_eq!(model_a.get_param(param::IISMethod).unwrap(), default + 1);
+  assert_eq!(model_b.get_param(param::IISMethod).unwrap(), default);
+}